@@ -1,43 +1,172 @@
 use std::marker::PhantomData;
 
 use halo2_proofs::{
-    arithmetic::FieldExt, circuit::*, dev::MockProver, halo2curves::pasta::Fp, plonk::*,
-    poly::Rotation,
+    arithmetic::FieldExt,
+    circuit::*,
+    dev::{cost::CircuitCost, MockProver},
+    halo2curves::pasta::{EqAffine, Fp},
+    plonk::*,
+    poly::{
+        commitment::{Params, ParamsVerifier},
+        Rotation,
+    },
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255, TranscriptReadBuffer, TranscriptWriterBuffer},
 };
+use rand_core::OsRng;
+
+/// Largest `n` the circuit will accept. Chosen so that `MAX_N!` stays well
+/// below both `u64::MAX` and the Pasta field modulus, so the native
+/// `factorial` helper and the in-circuit accumulator never silently wrap.
+pub const MAX_N: u64 = 20;
+
+/// A two-advice-column recurrence `acc_{i+1} = f(acc_i, b_i)`,
+/// `b_{i+1} = g(b_i)`, seeded from `b_0 = h(acc_0)`. Implementing this trait
+/// for a marker type turns [`IteratedOpChip`] into a chip for that specific
+/// fold: factorials (`f = *`, `g = pred`), falling factorials and sums of a
+/// `g`-generated sequence (see [`FallingSumOp`]) all share the same region
+/// layout and only differ in `f`/`g`/`h`. Every `b_i` is derived from `b_0`
+/// through `g` alone; there is no per-row input, so a running product of an
+/// arbitrary sequence cannot be expressed with this trait.
+pub trait IteratedOp<F: FieldExt> {
+    /// Name of the `create_gate` this op installs, shown in circuit errors.
+    const NAME: &'static str;
+
+    /// Constrains `acc_next` (`f`) in terms of `acc_cur` and `b_cur`.
+    fn acc_constraint(
+        acc_cur: Expression<F>,
+        b_cur: Expression<F>,
+        acc_next: Expression<F>,
+    ) -> Expression<F>;
+
+    /// Constrains `b_next` (`g`) in terms of `b_cur`.
+    fn step_constraint(b_cur: Expression<F>, b_next: Expression<F>) -> Expression<F>;
+
+    /// Witness-side counterpart of [`Self::acc_constraint`].
+    fn acc_value(acc_cur: F, b_cur: F) -> F;
+
+    /// Witness-side counterpart of [`Self::step_constraint`].
+    fn step_value(b_cur: F) -> F;
+
+    /// Witness-side counterpart of the seed `h`: computes `b_0` from the
+    /// instance-loaded `acc_0`.
+    fn init_step_value(acc_0: F) -> F;
+}
+
+/// [`IteratedOp`] instantiation computing `n!`: `acc_{i+1} = acc_i * b_i`,
+/// `b_{i+1} = b_i - 1`, seeded from `b_0 = acc_0 - 1`.
+#[derive(Debug, Clone)]
+pub struct FactorialOp;
+
+impl<F: FieldExt> IteratedOp<F> for FactorialOp {
+    const NAME: &'static str = "factorial";
+
+    fn acc_constraint(
+        acc_cur: Expression<F>,
+        b_cur: Expression<F>,
+        acc_next: Expression<F>,
+    ) -> Expression<F> {
+        acc_next - (acc_cur * b_cur)
+    }
+
+    fn step_constraint(b_cur: Expression<F>, b_next: Expression<F>) -> Expression<F> {
+        let one = Expression::Constant(F::one());
+        b_cur - (b_next + one)
+    }
 
+    fn acc_value(acc_cur: F, b_cur: F) -> F {
+        acc_cur * b_cur
+    }
+
+    fn step_value(b_cur: F) -> F {
+        b_cur - F::one()
+    }
+
+    fn init_step_value(acc_0: F) -> F {
+        acc_0 - F::one()
+    }
+}
+
+/// [`IteratedOp`] instantiation computing a falling sum
+/// `n + (n-1) + (n-2) + ...`: `acc_{i+1} = acc_i + b_i`, `b_{i+1} = b_i - 1`,
+/// seeded from `b_0 = acc_0 - 1`. Shares [`FactorialOp`]'s step recurrence
+/// but accumulates by addition instead of multiplication, demonstrating that
+/// [`IteratedOpChip`] isn't factorial-specific.
 #[derive(Debug, Clone)]
-struct FactorialConfig {
+pub struct FallingSumOp;
+
+impl<F: FieldExt> IteratedOp<F> for FallingSumOp {
+    const NAME: &'static str = "falling sum";
+
+    fn acc_constraint(
+        acc_cur: Expression<F>,
+        b_cur: Expression<F>,
+        acc_next: Expression<F>,
+    ) -> Expression<F> {
+        acc_next - (acc_cur + b_cur)
+    }
+
+    fn step_constraint(b_cur: Expression<F>, b_next: Expression<F>) -> Expression<F> {
+        let one = Expression::Constant(F::one());
+        b_cur - (b_next + one)
+    }
+
+    fn acc_value(acc_cur: F, b_cur: F) -> F {
+        acc_cur + b_cur
+    }
+
+    fn step_value(b_cur: F) -> F {
+        b_cur - F::one()
+    }
+
+    fn init_step_value(acc_0: F) -> F {
+        acc_0 - F::one()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IteratedOpConfig {
     pub advice: [Column<Advice>; 2],
     pub selector: Selector,
     pub instance: Column<Instance>,
+    pub q_lookup: Selector,
+    pub table: TableColumn,
 }
 
+/// Chip for the recurrence described by [`IteratedOp`]. `FactorialChip` is
+/// the instantiation with [`FactorialOp`].
 #[derive(Debug, Clone)]
-struct FactorialChip<F: FieldExt> {
-    config: FactorialConfig,
+struct IteratedOpChip<F: FieldExt, Op: IteratedOp<F>> {
+    config: IteratedOpConfig,
+    _op: PhantomData<Op>,
     _marker: PhantomData<F>,
 }
 
-impl<F: FieldExt> FactorialChip<F> {
-    pub fn construct(config: FactorialConfig) -> Self {
+type FactorialConfig = IteratedOpConfig;
+type FactorialChip<F> = IteratedOpChip<F, FactorialOp>;
+
+impl<F: FieldExt, Op: IteratedOp<F>> IteratedOpChip<F, Op> {
+    pub fn construct(config: IteratedOpConfig) -> Self {
         Self {
             config,
+            _op: PhantomData,
             _marker: PhantomData,
         }
     }
 
-    pub fn configure(meta: &mut ConstraintSystem<F>) -> FactorialConfig {
+    pub fn configure(meta: &mut ConstraintSystem<F>) -> IteratedOpConfig {
         let col_a = meta.advice_column();
         let col_b = meta.advice_column();
         let q0 = meta.selector();
         let q1 = meta.selector();
+        let q_lookup = meta.complex_selector();
         let instance = meta.instance_column();
+        let table = meta.lookup_table_column();
 
         meta.enable_equality(col_a);
         meta.enable_equality(col_b);
         meta.enable_equality(instance);
 
-        meta.create_gate("factorial", |meta| {
+        meta.create_gate(Op::NAME, |meta| {
             /* layout
 
             col_a   |   col_b   |   instance
@@ -53,39 +182,77 @@ impl<F: FieldExt> FactorialChip<F> {
             let c = meta.query_advice(col_a, Rotation::next());
             let d = meta.query_advice(col_b, Rotation::next());
 
-            /* constraint setup
-
-            general checks
-            c = a * b
-            d = b - 1
-            resulting constraints:
-            b - (d + 1) == 0
-            c - (a * b) == 0
-
-            */
-            let one = Expression::Constant(F::one());
+            // Both constraints are gated by `q0`, the selector `calculate`
+            // enables; `q1` is never enabled, so gating on it would leave the
+            // accumulator unconstrained.
+            let _ = s1;
             vec![
-                s0 * (b.clone() - (d + one)),
-                s1 * (c - (a * b))
+                s0.clone() * Op::step_constraint(b.clone(), d),
+                s0 * Op::acc_constraint(a, b, c),
             ]
         });
 
-        FactorialConfig {
+        // Range check: wherever `q_lookup` is enabled, `col_a` must appear in
+        // `table`. This is only turned on for the instance-loaded `init`
+        // value (row 0), so out-of-range `n` fails proving instead of
+        // silently wrapping.
+        meta.lookup("init is in range", |meta| {
+            let q_lookup = meta.query_selector(q_lookup);
+            let not_q_lookup = Expression::Constant(F::one()) - q_lookup.clone();
+            let init = meta.query_advice(col_a, Rotation::cur());
+
+            vec![(q_lookup * init + not_q_lookup * Expression::Constant(F::zero()), table)]
+        });
+
+        IteratedOpConfig {
             advice: [col_a, col_b],
             selector: q0,
             instance,
+            q_lookup,
+            table,
         }
     }
 
+    /// Populates the range-check lookup table with every valid input
+    /// `0..=max_n`.
+    pub fn load_table(&self, mut layouter: impl Layouter<F>, max_n: u64) -> Result<(), Error> {
+        layouter.assign_table(
+            || "n range check table",
+            |mut table| {
+                for (offset, value) in (0..=max_n).enumerate() {
+                    table.assign_cell(
+                        || "num",
+                        self.config.table,
+                        offset,
+                        || Value::known(F::from(value)),
+                    )?;
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Lays out `nrows` rows (always at least row 0), applying the recurrence
+    /// `nrows - 1` times. `checkpoint_rows` selects which
+    /// rows' `col_a` accumulator cells are also returned (in ascending row
+    /// order, tagged with their row) so the caller can expose them as extra
+    /// public outputs via [`Self::expose_intermediates`].
     pub fn calculate(
         &self,
         mut layouter: impl Layouter<F>,
         nrows: usize,
-    ) -> Result<AssignedCell<F, F>, Error> {
+        checkpoint_rows: &[usize],
+    ) -> Result<(AssignedCell<F, F>, Vec<(usize, AssignedCell<F, F>)>), Error> {
         layouter.assign_region(
-            || "factorial table",
+            || "iterated op table",
             |mut region| {
-                let _ = self.config.selector.enable(&mut region, 0);
+                // Row 0's step constraint reaches into row 1 via `Rotation::next()`, so
+                // only enable it when a row 1 actually gets assigned below; otherwise
+                // `nrows <= 1` leaves that cell unassigned and the gate unsatisfiable.
+                if nrows > 1 {
+                    let _ = self.config.selector.enable(&mut region, 0);
+                }
+                let _ = self.config.q_lookup.enable(&mut region, 0);
 
                 let mut a_cell = region.assign_advice_from_instance(
                     || "a",
@@ -99,9 +266,14 @@ impl<F: FieldExt> FactorialChip<F> {
                     || "b",
                     self.config.advice[1],
                     0,
-                    || a_cell.value().map(|a| *a - F::one()),
+                    || a_cell.value().map(|a| Op::init_step_value(*a)),
                 )?;
 
+                let mut checkpoints = Vec::new();
+                if checkpoint_rows.contains(&0) {
+                    checkpoints.push((0, a_cell.clone()));
+                }
+
                 for row in 1..nrows {
                     if row < nrows - 1 {
                         let _ = self.config.selector.enable(&mut region, row);
@@ -111,21 +283,29 @@ impl<F: FieldExt> FactorialChip<F> {
                         || "c",
                         self.config.advice[0],
                         row,
-                        || a_cell.value().and_then(|a| b_cell.value().map(|b| *a * *b)),
+                        || {
+                            a_cell
+                                .value()
+                                .and_then(|a| b_cell.value().map(|b| Op::acc_value(*a, *b)))
+                        },
                     )?;
 
                     let d_cell = region.assign_advice(
                         || "d",
                         self.config.advice[1],
                         row,
-                        || b_cell.value().map(|b| *b - F::one()),
+                        || b_cell.value().map(|b| Op::step_value(*b)),
                     )?;
 
                     a_cell = c_cell;
                     b_cell = d_cell;
+
+                    if checkpoint_rows.contains(&row) {
+                        checkpoints.push((row, a_cell.clone()));
+                    }
                 }
 
-                Ok(a_cell)
+                Ok((a_cell, checkpoints))
             },
         )
     }
@@ -138,11 +318,29 @@ impl<F: FieldExt> FactorialChip<F> {
     ) -> Result<(), Error> {
         layouter.constrain_instance(cell.cell(), self.config.instance, row)
     }
+
+    /// Constrains each `(cell, instance_row)` checkpoint cell to the given
+    /// instance row, exposing it as an additional public output.
+    pub fn expose_intermediates(
+        &self,
+        mut layouter: impl Layouter<F>,
+        checkpoints: &[(AssignedCell<F, F>, usize)],
+    ) -> Result<(), Error> {
+        for (cell, instance_row) in checkpoints {
+            layouter.constrain_instance(cell.cell(), self.config.instance, *instance_row)?;
+        }
+        Ok(())
+    }
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 struct FactorialCircuit<F> {
     n: usize,
+    /// `(row, instance_row)` checkpoints: the `col_a` accumulator cell at
+    /// `row` is additionally constrained to instance row `instance_row`, so
+    /// a verifier can check intermediate partial products alongside the
+    /// final `n!` at instance row 1.
+    checkpoints: Vec<(usize, usize)>,
     _marker: PhantomData<F>,
 }
 
@@ -164,29 +362,133 @@ impl<F: FieldExt> Circuit<F> for FactorialCircuit<F> {
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
         let chip = FactorialChip::construct(config);
-        let output = chip.calculate(layouter.namespace(|| "output"), self.n)?;
+        chip.load_table(layouter.namespace(|| "load range check table"), MAX_N)?;
+
+        let checkpoint_rows: Vec<usize> = self.checkpoints.iter().map(|(row, _)| *row).collect();
+        let (output, intermediates) =
+            chip.calculate(layouter.namespace(|| "output"), self.n, &checkpoint_rows)?;
+
+        chip.expose_public(layouter.namespace(|| "expose output"), output, 1)?;
+
+        // Every requested checkpoint row must have produced an intermediate
+        // cell; a row outside `0..self.n` would otherwise silently drop its
+        // constraint and leave that instance slot unconstrained.
+        let exposed: Vec<(AssignedCell<F, F>, usize)> = self
+            .checkpoints
+            .iter()
+            .map(|(row, instance_row)| {
+                intermediates
+                    .iter()
+                    .find(|(r, _)| r == row)
+                    .map(|(_, cell)| (cell.clone(), *instance_row))
+                    .ok_or(Error::Synthesis)
+            })
+            .collect::<Result<_, Error>>()?;
+        chip.expose_intermediates(layouter.namespace(|| "expose intermediates"), &exposed)?;
 
-        let _ = chip.expose_public(layouter.namespace(|| "expose output"), output, 1)?;
         Ok(())
     }
 }
 
-/// Calculates `n` factorial to be passed as public input
+/// Calculates `n` factorial to be passed as public input. Multiplies in the
+/// field rather than in `u64` so this never panics on `u64` overflow, even
+/// for `n` beyond [`MAX_N`] (the in-circuit lookup is what rejects those,
+/// not this helper); once `n!` exceeds the field modulus (`n >= 57`) the
+/// result is reduced modulo it. For `n = 0` it returns `0`, the value the
+/// circuit exposes, not `0! = 1`.
 pub fn factorial(n: u64) -> Fp {
-    Fp::from((1..n - 1).into_iter().fold(n, |acc, i| acc * (n - i)))
+    if n == 0 {
+        // `calculate` runs with `nrows = n`, so `nrows = 0` never enters the
+        // recurrence loop and the exposed output is just the unchanged
+        // instance-loaded `init` (`n`), i.e. `0` — not the mathematical `0!`.
+        return Fp::from(0);
+    }
+
+    (1..n - 1)
+        .into_iter()
+        .fold(Fp::from(n), |acc, i| acc * Fp::from(n - i))
+}
+
+/// Minimum `k` such that `2^k` rows can hold the `n` rows `calculate` lays
+/// out (or the fixed `MAX_N + 1`-row lookup table `load_table` always
+/// assigns, whichever is larger) plus the circuit's blinding rows, so
+/// callers can size `k` from `n` instead of hard-coding a magic constant.
+pub fn min_k_for_n(n: usize) -> u32 {
+    let mut meta = ConstraintSystem::<Fp>::default();
+    FactorialChip::<Fp>::configure(&mut meta);
+    let min_rows = n.max(MAX_N as usize + 1) + meta.blinding_factors() + 1;
+
+    let mut k = 1;
+    while (1usize << k) < min_rows {
+        k += 1;
+    }
+    k
+}
+
+/// Reports gate/advice/lookup usage and the estimated proof size for
+/// `circuit` at the given `k`, so a caller can sanity-check the cost of a
+/// chosen `n` before proving.
+pub fn circuit_cost(
+    k: u32,
+    circuit: &FactorialCircuit<Fp>,
+) -> CircuitCost<EqAffine, FactorialCircuit<Fp>> {
+    CircuitCost::measure(k, circuit)
+}
+
+/// Runs the full PLONK proving pipeline for a [`FactorialCircuit`] over the
+/// Pasta `EqAffine` curve: builds IPA `Params` for `k`, derives the
+/// verifying/proving keys, and produces a serialized proof over
+/// `public_inputs`.
+pub fn prove(
+    k: u32,
+    circuit: &FactorialCircuit<Fp>,
+    public_inputs: &[Fp],
+) -> Result<Vec<u8>, Error> {
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, circuit)?;
+    let pk = keygen_pk(&params, vk, circuit)?;
+
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit.clone()],
+        &[&[public_inputs]],
+        OsRng,
+        &mut transcript,
+    )?;
+    Ok(transcript.finalize())
+}
+
+/// Verifies a proof produced by [`prove`] against `public_inputs`, returning
+/// `Ok(())` when the proof is valid.
+pub fn verify(
+    k: u32,
+    vk: &VerifyingKey<EqAffine>,
+    proof: &[u8],
+    public_inputs: &[Fp],
+) -> Result<(), Error> {
+    let params: Params<EqAffine> = Params::new(k);
+    let params_verifier: ParamsVerifier<EqAffine> = params.verifier(public_inputs.len())?;
+    let strategy = SingleVerifier::new(&params_verifier);
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(proof);
+    verify_proof(&params_verifier, vk, strategy, &[&[public_inputs]], &mut transcript)
 }
 
 fn main() {
     let arg = 6;
     let circuit: FactorialCircuit<Fp> = FactorialCircuit {
         n: arg,
+        checkpoints: vec![],
         _marker: PhantomData,
     };
 
     let expected_output = factorial(arg as u64);
 
     let public_inputs = vec![Fp::from(arg as u64), expected_output];
-    let k = 4;
+    let k = min_k_for_n(arg);
+
+    println!("{:#?}", circuit_cost(k, &circuit));
 
     use plotters::prelude::*;
     let root = BitMapBackend::new("layout.png", (1024, 768)).into_drawing_area();
@@ -209,4 +511,214 @@ fn main() {
     // Given the correct public input, our circuit will verify.
     let prover = MockProver::run(k, &circuit, vec![public_inputs.clone()]).unwrap();
     assert_eq!(prover.verify(), Ok(()));
+
+    // Run the real PLONK pipeline: generate a proof and verify it.
+    let proof = prove(k, &circuit, &public_inputs).unwrap();
+    let params: Params<EqAffine> = Params::new(k);
+    let vk = keygen_vk(&params, &circuit).unwrap();
+    assert!(verify(k, &vk, &proof, &public_inputs).is_ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn n_zero_verifies() {
+        let n = 0;
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64)];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn n_within_range_verifies() {
+        let n = MAX_N as usize;
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64)];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn n_out_of_range_fails_proving() {
+        let n = (MAX_N + 1) as usize;
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64)];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    /// Mirrors the chip's recurrence natively to compute the expected
+    /// `col_a` value at a given row, for asserting against checkpoints.
+    fn expected_checkpoint(n: u64, row: usize) -> u64 {
+        let mut a = n;
+        let mut b = n - 1;
+        for _ in 1..=row {
+            let c = a * b;
+            let d = b - 1;
+            a = c;
+            b = d;
+        }
+        a
+    }
+
+    #[test]
+    fn exposes_intermediate_checkpoint() {
+        let n = 6;
+        let row = 3;
+        let partial = Fp::from(expected_checkpoint(n as u64, row));
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![(row, 2)],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64), partial];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
+
+    #[test]
+    fn wrong_intermediate_checkpoint_fails() {
+        let n = 6;
+        let row = 3;
+        let wrong_partial = Fp::from(expected_checkpoint(n as u64, row) + 1);
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![(row, 2)],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64), wrong_partial];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn checkpoint_row_outside_nrows_errors() {
+        let n = 6;
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![(n, 2)],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64), Fp::from(0)];
+
+        assert!(MockProver::run(5, &circuit, vec![public_inputs]).is_err());
+    }
+
+    #[test]
+    fn prove_verify_round_trip() {
+        let n = 6;
+        let k = min_k_for_n(n);
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64)];
+
+        let proof = prove(k, &circuit, &public_inputs).unwrap();
+
+        let params: Params<EqAffine> = Params::new(k);
+        let vk = keygen_vk(&params, &circuit).unwrap();
+        assert!(verify(k, &vk, &proof, &public_inputs).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_tampered_proof() {
+        let n = 6;
+        let k = min_k_for_n(n);
+        let circuit: FactorialCircuit<Fp> = FactorialCircuit {
+            n,
+            checkpoints: vec![],
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), factorial(n as u64)];
+
+        let mut proof = prove(k, &circuit, &public_inputs).unwrap();
+        let last = proof.len() - 1;
+        proof[last] ^= 0xff;
+
+        let params: Params<EqAffine> = Params::new(k);
+        let vk = keygen_vk(&params, &circuit).unwrap();
+        assert!(verify(k, &vk, &proof, &public_inputs).is_err());
+    }
+
+    /// A second, non-factorial [`IteratedOp`] circuit, to exercise
+    /// [`IteratedOpChip`] generically rather than only through
+    /// [`FactorialChip`].
+    #[derive(Default, Debug, Clone)]
+    struct FallingSumCircuit<F> {
+        n: usize,
+        _marker: PhantomData<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for FallingSumCircuit<F> {
+        type Config = IteratedOpConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+            IteratedOpChip::<F, FallingSumOp>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = IteratedOpChip::<F, FallingSumOp>::construct(config);
+            chip.load_table(layouter.namespace(|| "load range check table"), MAX_N)?;
+            let (output, _) = chip.calculate(layouter.namespace(|| "output"), self.n, &[])?;
+            chip.expose_public(layouter.namespace(|| "expose output"), output, 1)?;
+            Ok(())
+        }
+    }
+
+    /// Mirrors [`FallingSumOp`]'s recurrence natively.
+    fn falling_sum(n: u64) -> u64 {
+        let mut a = n;
+        let mut b = n - 1;
+        for _ in 1..n {
+            let c = a + b;
+            let d = b - 1;
+            a = c;
+            b = d;
+        }
+        a
+    }
+
+    #[test]
+    fn falling_sum_op_verifies() {
+        let n = 6;
+        let circuit: FallingSumCircuit<Fp> = FallingSumCircuit {
+            n,
+            _marker: PhantomData,
+        };
+        let public_inputs = vec![Fp::from(n as u64), Fp::from(falling_sum(n as u64))];
+
+        let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+    }
 }